@@ -2,14 +2,19 @@ use std::{
     fs::File,
     io::{self, BufWriter, Write},
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 
+use crossbeam_channel::{bounded, Sender, TrySendError};
 use parking_lot::{Mutex, MutexGuard};
 use time::{
     format_description::{parse_owned, Component, OwnedFormatItem},
     Date, Duration, OffsetDateTime, Time, UtcOffset,
 };
+use tracing::{Level, Metadata};
 use tracing_subscriber::fmt::MakeWriter;
 
 pub trait Checker: Sized {
@@ -31,6 +36,30 @@ pub trait Checker: Sized {
         }
     }
 
+    /// a counter [`Rolling`] increments with every byte actually written,
+    /// consulted by size-based checkers such as [`BySize`]; checkers that
+    /// don't care about accumulated size can keep the default
+    fn size_counter(&self) -> Option<Arc<AtomicU64>> {
+        None
+    }
+
+    /// path of the file currently being written to, if any; used by
+    /// [`Rolling::update_writer`] to know what it is retiring on rotation
+    fn current_path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// called by [`Rolling::update_writer`] right after rotation with the
+    /// path of the file just retired; [`Compress`] uses this to ship the
+    /// file off to a background worker
+    fn on_retire(&self, _path: PathBuf) {}
+
+    /// compress each rotated file in the background once it's retired
+    fn compress(self, compression: Compression) -> Compress<Self> {
+        Compress::new(self, compression)
+    }
+
+    #[allow(clippy::type_complexity)]
     fn build(self) -> io::Result<(Rolling<Self, Self::W>, Token<Self::W>)> {
         let fd = Arc::new(Mutex::new(self.new_writer()?));
         let t = Token(fd.clone());
@@ -61,16 +90,30 @@ impl<C: Checker<W = W>, W: Write> Rolling<C, W> {
     }
 
     fn update_writer(&self) -> io::Result<()> {
+        let retiring = self.checker.current_path();
         {
             let mut writer = self.writer.lock();
             writer.flush()?;
         }
         let writer = self.checker.new_writer()?;
         *self.writer.lock() = writer;
+        if let Some(path) = retiring {
+            self.checker.on_retire(path);
+        }
         Ok(())
     }
 }
 
+impl<C: Checker<W = W> + Send + 'static, W: Write + Send + 'static> Rolling<C, W> {
+    /// move this `Rolling` onto a dedicated worker thread and return a
+    /// cheap, cloneable [`MakeWriter`] that ships formatted events to it
+    /// over a bounded channel, so slow disk I/O never blocks the caller.
+    /// Equivalent to `NonBlockingBuilder::default().finish(self)`.
+    pub fn non_blocking(self) -> (NonBlocking, NonBlockingGuard) {
+        NonBlockingBuilder::default().finish(self)
+    }
+}
+
 impl<'a, C: Checker<W = W>, W: Write + 'a> MakeWriter<'a> for Rolling<C, W> {
     type Writer = RollingWriter<'a, W>;
 
@@ -80,19 +123,182 @@ impl<'a, C: Checker<W = W>, W: Write + 'a> MakeWriter<'a> for Rolling<C, W> {
                 eprintln!("can not update log file {e}")
             }
         }
-        RollingWriter(self.writer.lock())
+        RollingWriter {
+            guard: self.writer.lock(),
+            size_counter: self.checker.size_counter(),
+        }
     }
 }
 
-pub struct RollingWriter<'a, W: Write>(MutexGuard<'a, W>);
+pub struct RollingWriter<'a, W: Write> {
+    guard: MutexGuard<'a, W>,
+    size_counter: Option<Arc<AtomicU64>>,
+}
 
 impl<'a, W: Write> Write for RollingWriter<'a, W> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.0.write(buf)
+        let n = self.guard.write(buf)?;
+        if let Some(counter) = &self.size_counter {
+            counter.fetch_add(n as u64, Ordering::Relaxed);
+        }
+        Ok(n)
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
-        self.0.flush()
+        self.guard.flush()
+    }
+}
+
+/// what to do with an event when the non-blocking channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowStrategy {
+    /// drop the event and log that it happened, rather than stall the caller
+    DropOnFull,
+    /// block the caller until the worker thread catches up
+    BlockOnFull,
+}
+
+enum NonBlockingMsg {
+    Write(Vec<u8>),
+    Flush(std::sync::mpsc::Sender<()>),
+}
+
+/// configures and builds a [`NonBlocking`] writer backed by a worker thread
+pub struct NonBlockingBuilder {
+    capacity: usize,
+    overflow: OverflowStrategy,
+}
+
+impl Default for NonBlockingBuilder {
+    fn default() -> Self {
+        Self {
+            capacity: 128_000,
+            overflow: OverflowStrategy::DropOnFull,
+        }
+    }
+}
+
+impl NonBlockingBuilder {
+    /// maximum number of buffered events before `overflow` kicks in
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// backpressure behaviour once `capacity` is reached
+    pub fn overflow(mut self, overflow: OverflowStrategy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// spawn the worker thread that owns `rolling` and returns the
+    /// [`MakeWriter`] handed to events, plus a guard that must be kept
+    /// alive (and dropped last) to flush on shutdown
+    pub fn finish<C, W>(self, rolling: Rolling<C, W>) -> (NonBlocking, NonBlockingGuard)
+    where
+        C: Checker<W = W> + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let (tx, rx) = bounded::<NonBlockingMsg>(self.capacity);
+        let handle = std::thread::Builder::new()
+            .name("tracing-rolling-non-blocking".into())
+            .spawn(move || {
+                for msg in rx {
+                    match msg {
+                        NonBlockingMsg::Write(buf) => {
+                            if let Err(e) = rolling.make_writer().write_all(&buf) {
+                                eprintln!("non_blocking: write failed: {e}");
+                            }
+                        }
+                        NonBlockingMsg::Flush(ack) => {
+                            if let Err(e) = rolling.make_writer().flush() {
+                                eprintln!("non_blocking: flush failed: {e}");
+                            }
+                            let _ = ack.send(());
+                        }
+                    }
+                }
+            })
+            .expect("spawn tracing-rolling non-blocking worker");
+        let writer = NonBlocking {
+            sender: tx.clone(),
+            overflow: self.overflow,
+        };
+        let guard = NonBlockingGuard {
+            sender: tx,
+            _handle: handle,
+        };
+        (writer, guard)
+    }
+}
+
+/// a cheap, cloneable [`MakeWriter`] that ships events to a dedicated
+/// writer thread instead of writing (and potentially blocking) inline
+#[derive(Clone)]
+pub struct NonBlocking {
+    sender: Sender<NonBlockingMsg>,
+    overflow: OverflowStrategy,
+}
+
+impl<'a> MakeWriter<'a> for NonBlocking {
+    type Writer = NonBlockingWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        NonBlockingWriter {
+            sender: self.sender.clone(),
+            overflow: self.overflow,
+        }
+    }
+}
+
+pub struct NonBlockingWriter {
+    sender: Sender<NonBlockingMsg>,
+    overflow: OverflowStrategy,
+}
+
+impl Write for NonBlockingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len();
+        let msg = NonBlockingMsg::Write(buf.to_vec());
+        match self.overflow {
+            OverflowStrategy::BlockOnFull => self.sender.send(msg).map_err(|_| {
+                io::Error::new(io::ErrorKind::BrokenPipe, "non-blocking worker gone")
+            })?,
+            OverflowStrategy::DropOnFull => match self.sender.try_send(msg) {
+                Ok(()) => {}
+                Err(TrySendError::Full(_)) => {
+                    eprintln!("non_blocking: channel full, dropping {len} bytes");
+                }
+                Err(TrySendError::Disconnected(_)) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::BrokenPipe,
+                        "non-blocking worker gone",
+                    ));
+                }
+            },
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // the worker owns the real file; see `NonBlockingGuard` for a
+        // synchronous flush on shutdown
+        Ok(())
+    }
+}
+
+#[must_use = "must manual drop to ensure remaining events are flushed when process exits"]
+pub struct NonBlockingGuard {
+    sender: Sender<NonBlockingMsg>,
+    _handle: std::thread::JoinHandle<()>,
+}
+
+impl Drop for NonBlockingGuard {
+    fn drop(&mut self) {
+        let (ack_tx, ack_rx) = std::sync::mpsc::channel();
+        if self.sender.send(NonBlockingMsg::Flush(ack_tx)).is_ok() {
+            let _ = ack_rx.recv();
+        }
     }
 }
 
@@ -101,19 +307,51 @@ pub trait Period {
     fn now(&self) -> OffsetDateTime;
     fn new_path(&self) -> String;
     fn duration(&self) -> &Duration;
+    /// directory the rolled files live in, used to scan for retention
+    fn dir(&self) -> &Path;
+    /// parse the date/time embedded in `path` using this period's format
+    fn parse_dt(&self, path: &str) -> Result<OffsetDateTime, String>;
+    /// the file currently being written to, or `None` before the first
+    /// file has been opened
+    fn current_file(&self) -> Option<PathBuf>;
+
+    /// keep at most `n` rolled files, deleting the oldest (by embedded
+    /// timestamp) whenever a new file is opened
+    fn max_files(self, n: usize) -> MaxFiles<Self>
+    where
+        Self: Sized,
+    {
+        MaxFiles::new(self, n)
+    }
+
+    /// roll to a new file once `limit` bytes have been written, in
+    /// addition to rolling on the period's own schedule
+    fn by_size(self, limit: u64) -> BySize<Self>
+    where
+        Self: Sized,
+    {
+        BySize::new(self, limit)
+    }
+}
+
+/// whether `period` is due to roll to a new file; shared between the
+/// blanket `Checker` impl below and [`BuiltChecker`], which can't use that
+/// blanket impl since it stores its period as an unsized trait object
+fn period_should_update<P: Period + ?Sized>(period: &P) -> bool {
+    let file_dt = match period.previous_dt() {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("parse previous file failed: {e}");
+            return false;
+        }
+    };
+    period.now() - file_dt >= *period.duration()
 }
 
 impl<P: Period> Checker for P {
     type W = File;
     fn should_update(&self) -> bool {
-        let file_dt = match self.previous_dt() {
-            Ok(v) => v,
-            Err(e) => {
-                eprintln!("parse previous file failed: {e}");
-                return false;
-            }
-        };
-        self.now() - file_dt >= *self.duration()
+        period_should_update(self)
     }
 
     fn new_writer(&self) -> io::Result<File> {
@@ -121,12 +359,17 @@ impl<P: Period> Checker for P {
         let file = File::options().append(true).create(true).open(path)?;
         Ok(file)
     }
+
+    fn current_path(&self) -> Option<PathBuf> {
+        self.current_file()
+    }
 }
 
 pub struct Minute {
     offset: UtcOffset,
     fmt: OwnedFormatItem,
     active: Mutex<String>,
+    dir: PathBuf,
 }
 
 impl Minute {
@@ -142,20 +385,24 @@ impl Minute {
             .as_ref()
             .with_extension(format!("[year]-[month]-[day]-[hour]-[minute].{ext}"));
         let fmt = parse_owned::<1>(&format!("{}", fmt.display())).unwrap();
+        let dir = path
+            .as_ref()
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
         Self {
             offset: offset.into().unwrap_or(UtcOffset::UTC),
             fmt,
             active: Default::default(),
+            dir,
         }
     }
 }
 
 impl Period for Minute {
     fn previous_dt(&self) -> Result<OffsetDateTime, String> {
-        let file = self.active.lock();
-        let date = Date::parse(&file, &self.fmt).map_err(|e| e.to_string())?;
-        let time = Time::parse(&file, &self.fmt).map_err(|e| e.to_string())?;
-        Ok(date.with_time(time).assume_offset(self.offset))
+        self.parse_dt(&self.active.lock())
     }
 
     fn now(&self) -> OffsetDateTime {
@@ -172,6 +419,21 @@ impl Period for Minute {
     fn duration(&self) -> &Duration {
         &Self::DURATION
     }
+
+    fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn parse_dt(&self, path: &str) -> Result<OffsetDateTime, String> {
+        let date = Date::parse(path, &self.fmt).map_err(|e| e.to_string())?;
+        let time = Time::parse(path, &self.fmt).map_err(|e| e.to_string())?;
+        Ok(date.with_time(time).assume_offset(self.offset))
+    }
+
+    fn current_file(&self) -> Option<PathBuf> {
+        let active = self.active.lock();
+        (!active.is_empty()).then(|| PathBuf::from(active.clone()))
+    }
 }
 
 pub struct Hourly {
@@ -179,6 +441,7 @@ pub struct Hourly {
     fmt: OwnedFormatItem,
     hour_regex: regex::Regex,
     active: Mutex<String>,
+    dir: PathBuf,
 }
 
 impl Hourly {
@@ -196,27 +459,25 @@ impl Hourly {
         let hour_regex =
             regex::Regex::new(&format!(r".*\d{{4}}-\d{{2}}-\d{{2}}-(\d{{2}})\.{ext}")).unwrap();
         let fmt = parse_owned::<1>(&format!("{}", fmt.display())).unwrap();
+        let dir = path
+            .as_ref()
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
         Self {
             offset: offset.into().unwrap_or(UtcOffset::UTC),
             fmt,
             active: Default::default(),
             hour_regex,
+            dir,
         }
     }
 }
 
 impl Period for Hourly {
     fn previous_dt(&self) -> Result<OffsetDateTime, String> {
-        let file = self.active.lock();
-        let date = Date::parse(&file, &self.fmt).map_err(|e| e.to_string())?;
-        let hour = self
-            .hour_regex
-            .captures(&file)
-            .and_then(|cap| cap.get(1))
-            .and_then(|m| m.as_str().parse::<u8>().ok())
-            .ok_or_else(|| format!("invalid hour component of {file}"))?;
-        let time = Time::from_hms(hour, 0, 0).unwrap();
-        Ok(date.with_time(time).assume_offset(self.offset))
+        self.parse_dt(&self.active.lock())
     }
 
     fn now(&self) -> OffsetDateTime {
@@ -233,17 +494,65 @@ impl Period for Hourly {
     fn duration(&self) -> &Duration {
         &Self::DURATION
     }
+
+    fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn parse_dt(&self, path: &str) -> Result<OffsetDateTime, String> {
+        let date = Date::parse(path, &self.fmt).map_err(|e| e.to_string())?;
+        let hour = self
+            .hour_regex
+            .captures(path)
+            .and_then(|cap| cap.get(1))
+            .and_then(|m| m.as_str().parse::<u8>().ok())
+            .ok_or_else(|| format!("invalid hour component of {path}"))?;
+        let time = Time::from_hms(hour, 0, 0).unwrap();
+        Ok(date.with_time(time).assume_offset(self.offset))
+    }
+
+    fn current_file(&self) -> Option<PathBuf> {
+        let active = self.active.lock();
+        (!active.is_empty()).then(|| PathBuf::from(active.clone()))
+    }
 }
 
 pub struct Daily {
     offset: UtcOffset,
     fmt: OwnedFormatItem,
     active: Mutex<String>,
+    dir: PathBuf,
 }
 
 impl Daily {
     pub const DURATION: Duration = Duration::DAY;
 
+    /// `[year]`/`[month]` parse to one of several granular `Component`
+    /// variants depending on modifiers, not the single `Year`/`Month`
+    /// variants (those only exist for matching hand-built descriptions)
+    fn is_year_component(c: &Component) -> bool {
+        matches!(
+            c,
+            Component::CalendarYearFullExtendedRange(_)
+                | Component::CalendarYearFullStandardRange(_)
+                | Component::IsoYearFullExtendedRange(_)
+                | Component::IsoYearFullStandardRange(_)
+                | Component::CalendarYearCenturyExtendedRange(_)
+                | Component::CalendarYearCenturyStandardRange(_)
+                | Component::IsoYearCenturyExtendedRange(_)
+                | Component::IsoYearCenturyStandardRange(_)
+                | Component::CalendarYearLastTwo(_)
+                | Component::IsoYearLastTwo(_)
+        )
+    }
+
+    fn is_month_component(c: &Component) -> bool {
+        matches!(
+            c,
+            Component::MonthShort(_) | Component::MonthLong(_) | Component::MonthNumerical(_)
+        )
+    }
+
     fn ensure_year_month_day(fmt: &OwnedFormatItem) {
         match fmt {
             OwnedFormatItem::Compound(items) => {
@@ -251,17 +560,14 @@ impl Daily {
                 let mut month = false;
                 let mut day = false;
                 for item in &items[..] {
-                    match item {
-                        OwnedFormatItem::Component(Component::Year(_)) => {
+                    if let OwnedFormatItem::Component(c) = item {
+                        if Self::is_year_component(c) {
                             year = !year;
-                        }
-                        OwnedFormatItem::Component(Component::Month(_)) => {
+                        } else if Self::is_month_component(c) {
                             month = !month;
-                        }
-                        OwnedFormatItem::Component(Component::Day(_)) => {
+                        } else if matches!(c, Component::Day(_)) {
                             day = !day;
                         }
-                        _ => {}
                     }
                 }
                 if !(year && month && day) {
@@ -306,21 +612,24 @@ impl Daily {
         ))
         .unwrap();
         Self::ensure_year_month_day(&fmt);
+        let dir = path
+            .as_ref()
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
         Self {
             offset: offset.into().unwrap_or(UtcOffset::UTC),
             fmt,
             active: Default::default(),
+            dir,
         }
     }
 }
 
 impl Period for Daily {
     fn previous_dt(&self) -> Result<OffsetDateTime, String> {
-        let file = self.active.lock();
-        let date = Date::parse(&file, &self.fmt).map_err(|e| e.to_string())?;
-        Ok(date
-            .with_time(time::macros::time!(0:0:0))
-            .assume_offset(self.offset))
+        self.parse_dt(&self.active.lock())
     }
 
     fn now(&self) -> OffsetDateTime {
@@ -337,6 +646,22 @@ impl Period for Daily {
     fn duration(&self) -> &Duration {
         &Self::DURATION
     }
+
+    fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn parse_dt(&self, path: &str) -> Result<OffsetDateTime, String> {
+        let date = Date::parse(path, &self.fmt).map_err(|e| e.to_string())?;
+        Ok(date
+            .with_time(time::macros::time!(0:0:0))
+            .assume_offset(self.offset))
+    }
+
+    fn current_file(&self) -> Option<PathBuf> {
+        let active = self.active.lock();
+        (!active.is_empty()).then(|| PathBuf::from(active.clone()))
+    }
 }
 
 pub struct Buffered<C: Checker<W = W>, W: Write> {
@@ -361,6 +686,345 @@ impl<C: Checker<W = W>, W: Write> Checker for Buffered<C, W> {
             self.checker.new_writer()?,
         ))
     }
+    fn size_counter(&self) -> Option<Arc<AtomicU64>> {
+        self.checker.size_counter()
+    }
+    fn current_path(&self) -> Option<PathBuf> {
+        self.checker.current_path()
+    }
+    fn on_retire(&self, path: PathBuf) {
+        self.checker.on_retire(path)
+    }
+}
+
+/// wraps a [`Period`], deleting the oldest rolled files once more than
+/// `max_files` exist in the period's directory
+pub struct MaxFiles<P: Period> {
+    period: P,
+    max_files: usize,
+}
+
+impl<P: Period> MaxFiles<P> {
+    pub fn new(period: P, max_files: usize) -> Self {
+        Self { period, max_files }
+    }
+
+    /// `exclude` is the path of the file about to be handed to `on_retire`
+    /// (if any); it must survive this cleanup pass even if it's the oldest
+    /// on disk, or a wrapping [`Compress`] would never get to read it
+    fn cleanup(&self, exclude: Option<&Path>) {
+        retain_max_files(&self.period, self.max_files, false, exclude);
+    }
+}
+
+/// if `name` carries a [`BySize`]/[`BuiltChecker`] disambiguating index
+/// (e.g. `app-2026-07-26.3.log`), strip it back out (`app-2026-07-26.log`)
+/// so it can be date-parsed against the period's own format
+fn strip_size_index(name: &str) -> String {
+    let path = Path::new(name);
+    let (Some(ext), Some(stem)) = (
+        path.extension().and_then(|e| e.to_str()),
+        path.file_stem().and_then(|s| s.to_str()),
+    ) else {
+        return name.to_string();
+    };
+    match stem.rsplit_once('.') {
+        Some((base, index)) if !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{base}.{ext}")
+        }
+        _ => name.to_string(),
+    }
+}
+
+/// if `name` carries a [`Compress`] compression extension (`.gz`/`.zst`),
+/// strip it back out so an already-compressed sibling still counts
+/// towards retention instead of silently accumulating forever
+fn strip_compress_ext(name: &str) -> String {
+    let path = Path::new(name);
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("gz") | Some("zst") => path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(name)
+            .to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// scan `period`'s directory, parse each entry's embedded timestamp using
+/// the period's format, and remove the oldest files in excess of
+/// `max_files`. logs but does not panic on I/O errors. `strip_index`
+/// should be set when the checker also does size-based rotation, so the
+/// disambiguating `.N` suffix doesn't break date parsing. a trailing
+/// [`Compress`] extension (`.gz`/`.zst`) is always stripped first, so
+/// compressed and not-yet-compressed files are retained together. `exclude`,
+/// if given, is never deleted regardless of age — used to protect a file
+/// that's about to be (but hasn't yet been) handed to `on_retire`.
+fn retain_max_files<P: Period + ?Sized>(
+    period: &P,
+    max_files: usize,
+    strip_index: bool,
+    exclude: Option<&Path>,
+) {
+    let dir = period.dir();
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("max_files: can not read dir {}: {e}", dir.display());
+            return;
+        }
+    };
+    let mut files = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if Some(path.as_path()) == exclude {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let name = strip_compress_ext(name);
+        let name = if strip_index {
+            strip_size_index(&name)
+        } else {
+            name
+        };
+        let candidate = dir.join(name).display().to_string();
+        if let Ok(dt) = period.parse_dt(&candidate) {
+            files.push((dt, path));
+        }
+    }
+    if files.len() <= max_files {
+        return;
+    }
+    files.sort_by_key(|(dt, _)| *dt);
+    for (_, path) in files.drain(..files.len() - max_files) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            eprintln!("max_files: can not remove {}: {e}", path.display());
+        }
+    }
+}
+
+impl<P: Period> Checker for MaxFiles<P> {
+    type W = File;
+
+    fn should_update(&self) -> bool {
+        self.period.should_update()
+    }
+
+    fn new_writer(&self) -> io::Result<File> {
+        let retiring = self.period.current_file();
+        let file = self.period.new_writer()?;
+        self.cleanup(retiring.as_deref());
+        Ok(file)
+    }
+
+    fn current_path(&self) -> Option<PathBuf> {
+        self.period.current_file()
+    }
+}
+
+/// wraps a [`Period`], additionally rolling to a new file once `limit`
+/// bytes have been written since the last rotation
+pub struct BySize<P: Period> {
+    period: P,
+    limit: u64,
+    written: Arc<AtomicU64>,
+    /// (base path returned by the period, next disambiguating index)
+    index: Mutex<(String, u64)>,
+    active: Mutex<Option<PathBuf>>,
+}
+
+impl<P: Period> BySize<P> {
+    pub fn new(period: P, limit: u64) -> Self {
+        Self {
+            period,
+            limit,
+            written: Arc::new(AtomicU64::new(0)),
+            index: Mutex::new((String::new(), 0)),
+            active: Mutex::new(None),
+        }
+    }
+}
+
+impl<P: Period> Checker for BySize<P> {
+    type W = File;
+
+    fn should_update(&self) -> bool {
+        self.period.should_update() || self.written.load(Ordering::Relaxed) >= self.limit
+    }
+
+    fn new_writer(&self) -> io::Result<File> {
+        self.written.store(0, Ordering::Relaxed);
+        let base = self.period.new_path();
+        let mut index = self.index.lock();
+        let next = if index.0 == base { index.1 + 1 } else { 1 };
+        *index = (base.clone(), next);
+        drop(index);
+
+        let path = Path::new(&base);
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        let path = path.with_extension(format!("{next}.{ext}"));
+        let file = File::options().append(true).create(true).open(&path)?;
+        *self.active.lock() = Some(path);
+        Ok(file)
+    }
+
+    fn size_counter(&self) -> Option<Arc<AtomicU64>> {
+        Some(self.written.clone())
+    }
+
+    fn current_path(&self) -> Option<PathBuf> {
+        self.active.lock().clone()
+    }
+}
+
+/// compression format applied to a retired log file by [`Compress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+}
+
+struct CompressJob {
+    path: PathBuf,
+    compression: Compression,
+    /// signalled once the worker has taken (or failed to take) ownership
+    /// of `path`, so [`Compress::on_retire`] can block until the file is
+    /// safe from a racing [`MaxFiles`] cleanup before returning
+    opened: std::sync::mpsc::Sender<()>,
+}
+
+fn compress_file(mut input: File, path: &Path, compression: Compression) -> io::Result<()> {
+    let out_path = match compression {
+        Compression::Gzip => {
+            let out_path = append_extension(path, "gz");
+            let out = File::create(&out_path)?;
+            let mut encoder =
+                flate2::write::GzEncoder::new(out, flate2::Compression::default());
+            io::copy(&mut input, &mut encoder)?;
+            encoder.finish()?;
+            out_path
+        }
+        Compression::Zstd => {
+            let out_path = append_extension(path, "zst");
+            let out = File::create(&out_path)?;
+            zstd::stream::copy_encode(input, out, 0)?;
+            out_path
+        }
+    };
+    if let Err(e) = std::fs::remove_file(path) {
+        eprintln!(
+            "compress: wrote {} but failed to remove original {}: {e}",
+            out_path.display(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn append_extension(path: &Path, ext: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(ext);
+    PathBuf::from(name)
+}
+
+/// wraps any [`Checker`], compressing each retired file on a background
+/// thread once [`Rolling`] rotates away from it
+pub struct Compress<C: Checker> {
+    checker: C,
+    compression: Compression,
+    /// `None` only after `Drop` has closed the channel and joined `handle`
+    jobs: Option<std::sync::mpsc::Sender<CompressJob>>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl<C: Checker> Compress<C> {
+    pub fn new(checker: C, compression: Compression) -> Self {
+        let (tx, rx) = std::sync::mpsc::channel::<CompressJob>();
+        let handle = std::thread::Builder::new()
+            .name("tracing-rolling-compress".into())
+            .spawn(move || {
+                for job in rx {
+                    match File::open(&job.path) {
+                        Ok(input) => {
+                            let _ = job.opened.send(());
+                            if let Err(e) = compress_file(input, &job.path, job.compression) {
+                                eprintln!("compress {}: {e}", job.path.display());
+                            }
+                        }
+                        Err(e) => {
+                            let _ = job.opened.send(());
+                            eprintln!("compress: can not open {}: {e}", job.path.display());
+                        }
+                    }
+                }
+            })
+            .expect("spawn tracing-rolling compression worker");
+        Self {
+            checker,
+            compression,
+            jobs: Some(tx),
+            handle: Some(handle),
+        }
+    }
+}
+
+impl<C: Checker> Drop for Compress<C> {
+    fn drop(&mut self) {
+        // drop the sender first so the worker's `for job in rx` loop ends
+        // once it has drained any still-queued jobs, then wait for it to
+        // actually finish so nothing is silently skipped on shutdown
+        self.jobs.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<C: Checker> Checker for Compress<C> {
+    type W = C::W;
+
+    fn should_update(&self) -> bool {
+        self.checker.should_update()
+    }
+
+    fn new_writer(&self) -> io::Result<Self::W> {
+        self.checker.new_writer()
+    }
+
+    fn size_counter(&self) -> Option<Arc<AtomicU64>> {
+        self.checker.size_counter()
+    }
+
+    fn current_path(&self) -> Option<PathBuf> {
+        self.checker.current_path()
+    }
+
+    fn on_retire(&self, path: PathBuf) {
+        let Some(jobs) = &self.jobs else {
+            return;
+        };
+        let (opened_tx, opened_rx) = std::sync::mpsc::channel();
+        let job = CompressJob {
+            path,
+            compression: self.compression,
+            opened: opened_tx,
+        };
+        if jobs.send(job).is_err() {
+            eprintln!("compress: worker thread gone, skipping a retired file");
+            return;
+        }
+        // block until the worker has opened (or failed to open) the file, so
+        // a wrapped checker's retention cleanup (e.g. `MaxFiles`, which runs
+        // inside the *next* call to `new_writer`) can't delete it out from
+        // under the compressor before it's had a chance to read it
+        let _ = opened_rx.recv();
+    }
 }
 
 /// construct a non rolling file
@@ -383,3 +1047,343 @@ impl ConstFile {
         Self(path.as_ref().to_path_buf())
     }
 }
+
+/// adds `.with_max_level()` / `.with_min_level()` to any [`MakeWriter`],
+/// so routing by level doesn't require hand-writing a metadata-matching
+/// closure for [`MakeWriterExt::with_filter`]
+///
+/// [`MakeWriterExt::with_filter`]: tracing_subscriber::fmt::writer::MakeWriterExt::with_filter
+pub trait RollingMakeWriterExt: for<'a> MakeWriter<'a> {
+    /// only write events at or above the given severity (i.e. at most as
+    /// verbose as `level`), e.g. `with_max_level(Level::WARN)` keeps
+    /// `WARN` and `ERROR` but drops `INFO`/`DEBUG`/`TRACE`
+    fn with_max_level(self, level: Level) -> WithMaxLevel<Self>
+    where
+        Self: Sized,
+    {
+        WithMaxLevel::new(self, level)
+    }
+
+    /// only write events at or below the given severity (i.e. at least as
+    /// verbose as `level`), e.g. `with_min_level(Level::DEBUG)` keeps
+    /// `DEBUG` and `TRACE` but drops `INFO`/`WARN`/`ERROR`
+    fn with_min_level(self, level: Level) -> WithMinLevel<Self>
+    where
+        Self: Sized,
+    {
+        WithMinLevel::new(self, level)
+    }
+}
+
+impl<M> RollingMakeWriterExt for M where M: for<'a> MakeWriter<'a> {}
+
+/// either the wrapped [`MakeWriter`]'s writer, or a no-op sink when the
+/// event's level didn't pass the threshold
+pub enum EitherWriter<A> {
+    Writer(A),
+    Sink(io::Sink),
+}
+
+impl<A: Write> Write for EitherWriter<A> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            EitherWriter::Writer(w) => w.write(buf),
+            EitherWriter::Sink(s) => s.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            EitherWriter::Writer(w) => w.flush(),
+            EitherWriter::Sink(s) => s.flush(),
+        }
+    }
+}
+
+pub struct WithMaxLevel<M> {
+    make_writer: M,
+    level: Level,
+}
+
+impl<M> WithMaxLevel<M> {
+    pub fn new(make_writer: M, level: Level) -> Self {
+        Self { make_writer, level }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for WithMaxLevel<M> {
+    type Writer = EitherWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        EitherWriter::Writer(self.make_writer.make_writer())
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        if meta.level() <= &self.level {
+            EitherWriter::Writer(self.make_writer.make_writer_for(meta))
+        } else {
+            EitherWriter::Sink(io::sink())
+        }
+    }
+}
+
+pub struct WithMinLevel<M> {
+    make_writer: M,
+    level: Level,
+}
+
+impl<M> WithMinLevel<M> {
+    pub fn new(make_writer: M, level: Level) -> Self {
+        Self { make_writer, level }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for WithMinLevel<M> {
+    type Writer = EitherWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        EitherWriter::Writer(self.make_writer.make_writer())
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        if meta.level() >= &self.level {
+            EitherWriter::Writer(self.make_writer.make_writer_for(meta))
+        } else {
+            EitherWriter::Sink(io::sink())
+        }
+    }
+}
+
+/// errors returned by [`RollingBuilder::build`]
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// the builder's configuration was invalid, e.g. an empty prefix
+    InvalidConfig(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::InvalidConfig(msg) => write!(f, "invalid rolling config: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::InvalidConfig(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// which [`Period`] a [`RollingBuilder`] should rotate on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Minute,
+    Hourly,
+    Daily,
+}
+
+/// boxed writer produced by [`RollingBuilder`], so the returned [`Rolling`]
+/// has the same concrete type whether or not buffering is enabled
+pub type BoxedWriter = Box<dyn Write + Send>;
+
+/// a single, discoverable entry point for configuring a rolling log file:
+/// directory, filename prefix/suffix, rotation period, UTC offset,
+/// buffering, retention and size limit are all plain setters here instead
+/// of positional constructor arguments or a combinator chain, so new
+/// options can be added without breaking existing callers
+pub struct RollingBuilder {
+    dir: PathBuf,
+    prefix: String,
+    suffix: String,
+    rotation: Rotation,
+    offset: UtcOffset,
+    date_fmt: Option<String>,
+    buffer_size: Option<usize>,
+    max_files: Option<usize>,
+    size_limit: Option<u64>,
+}
+
+impl Default for RollingBuilder {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("."),
+            prefix: "app".to_string(),
+            suffix: "log".to_string(),
+            rotation: Rotation::Daily,
+            offset: UtcOffset::UTC,
+            date_fmt: None,
+            buffer_size: None,
+            max_files: None,
+            size_limit: None,
+        }
+    }
+}
+
+impl RollingBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// directory the rolled files are written to, default `.`
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    /// filename prefix, e.g. `app` in `app-2023-03-23.log`, default `app`
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// filename extension, e.g. `log` in `app-2023-03-23.log`, default `log`
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// rotation period, default [`Rotation::Daily`]
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// UTC offset used to format timestamps, default [`UtcOffset::UTC`]
+    pub fn offset(mut self, offset: UtcOffset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// custom date format, only used with [`Rotation::Daily`]; see
+    /// [`Daily::new`] for the format syntax
+    pub fn date_fmt(mut self, fmt: impl Into<String>) -> Self {
+        self.date_fmt = Some(fmt.into());
+        self
+    }
+
+    /// buffer writes with the given capacity instead of writing straight
+    /// through to the file
+    pub fn buffer_size(mut self, size: usize) -> Self {
+        self.buffer_size = Some(size);
+        self
+    }
+
+    /// keep at most `n` rolled files, deleting the oldest on rotation
+    pub fn max_files(mut self, n: usize) -> Self {
+        self.max_files = Some(n);
+        self
+    }
+
+    /// additionally roll once `limit` bytes have been written
+    pub fn size_limit(mut self, limit: u64) -> Self {
+        self.size_limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> Result<(Rolling<BuiltChecker, BoxedWriter>, Token<BoxedWriter>), Error> {
+        if self.prefix.is_empty() {
+            return Err(Error::InvalidConfig("prefix must not be empty".to_string()));
+        }
+        let path = self.dir.join(format!("{}.{}", self.prefix, self.suffix));
+        let period: Box<dyn Period + Send + Sync> = match self.rotation {
+            Rotation::Minute => Box::new(Minute::new(&path, self.offset)),
+            Rotation::Hourly => Box::new(Hourly::new(&path, self.offset)),
+            Rotation::Daily => Box::new(Daily::new::<String>(&path, self.date_fmt, self.offset)),
+        };
+        let checker = BuiltChecker {
+            period,
+            max_files: self.max_files,
+            size_limit: self.size_limit,
+            written: Arc::new(AtomicU64::new(0)),
+            buffer_size: self.buffer_size,
+            index: Mutex::new((String::new(), 0)),
+        };
+        Ok(checker.build()?)
+    }
+}
+
+/// the [`Checker`] assembled by [`RollingBuilder`]; folds period-based
+/// rotation, size-based rotation, retention and buffering into a single
+/// type so the builder has one concrete return type regardless of which
+/// options were set
+pub struct BuiltChecker {
+    period: Box<dyn Period + Send + Sync>,
+    max_files: Option<usize>,
+    size_limit: Option<u64>,
+    written: Arc<AtomicU64>,
+    buffer_size: Option<usize>,
+    /// (base path returned by the period, next disambiguating index),
+    /// only used when `size_limit` is set
+    index: Mutex<(String, u64)>,
+}
+
+impl Checker for BuiltChecker {
+    type W = BoxedWriter;
+
+    fn should_update(&self) -> bool {
+        let size_due = self
+            .size_limit
+            .is_some_and(|limit| self.written.load(Ordering::Relaxed) >= limit);
+        period_should_update(self.period.as_ref()) || size_due
+    }
+
+    fn new_writer(&self) -> io::Result<BoxedWriter> {
+        self.written.store(0, Ordering::Relaxed);
+        let base = self.period.new_path();
+        let path = if self.size_limit.is_some() {
+            let mut index = self.index.lock();
+            let next = if index.0 == base { index.1 + 1 } else { 1 };
+            *index = (base.clone(), next);
+            drop(index);
+            let base_path = Path::new(&base);
+            let ext = base_path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or_default();
+            base_path.with_extension(format!("{next}.{ext}"))
+        } else {
+            PathBuf::from(&base)
+        };
+        let file = File::options().append(true).create(true).open(&path)?;
+        if let Some(n) = self.max_files {
+            retain_max_files(self.period.as_ref(), n, self.size_limit.is_some(), None);
+        }
+        let writer: BoxedWriter = match self.buffer_size {
+            Some(size) => Box::new(BufWriter::with_capacity(size, file)),
+            None => Box::new(file),
+        };
+        Ok(writer)
+    }
+
+    fn size_counter(&self) -> Option<Arc<AtomicU64>> {
+        self.size_limit.is_some().then(|| self.written.clone())
+    }
+
+    fn current_path(&self) -> Option<PathBuf> {
+        if self.size_limit.is_none() {
+            return self.period.current_file();
+        }
+        let index = self.index.lock();
+        if index.0.is_empty() {
+            return None;
+        }
+        let base_path = Path::new(&index.0);
+        let ext = base_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        Some(base_path.with_extension(format!("{}.{ext}", index.1)))
+    }
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::io::Write;
+
+use tracing_rolling::{RollingBuilder, Rotation};
+use tracing_subscriber::fmt::MakeWriter;
+
+mod common;
+use common::make_temp_dir;
+
+#[test]
+fn rolling_builder_rejects_empty_prefix() {
+    assert!(RollingBuilder::new().prefix("").build().is_err());
+}
+
+#[test]
+fn rolling_builder_builds_and_writes() {
+    let dir = make_temp_dir("builder");
+    let (rolling, token) = RollingBuilder::new()
+        .dir(&dir)
+        .prefix("svc")
+        .suffix("log")
+        .rotation(Rotation::Daily)
+        .buffer_size(4096)
+        .build()
+        .unwrap();
+    {
+        let mut w = rolling.make_writer();
+        w.write_all(b"hello\n").unwrap();
+        w.flush().unwrap();
+    }
+    drop(token);
+
+    let has_log_file = fs::read_dir(&dir)
+        .unwrap()
+        .any(|e| e.unwrap().file_name().to_string_lossy().starts_with("svc"));
+    assert!(has_log_file);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn rolling_builder_retention_keeps_n_newest_size_rotated_files() {
+    let dir = make_temp_dir("builder_retention");
+    let (rolling, token) = RollingBuilder::new()
+        .dir(&dir)
+        .prefix("svc")
+        .rotation(Rotation::Daily)
+        .size_limit(1)
+        .max_files(2)
+        .build()
+        .unwrap();
+
+    for _ in 0..5 {
+        let mut w = rolling.make_writer();
+        w.write_all(b"0123456789").unwrap();
+        w.flush().unwrap();
+    }
+    drop(token);
+
+    let names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(
+        names.len(),
+        2,
+        "max_files(2) should cap size-rotated files, found {names:?}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
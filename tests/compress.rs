@@ -0,0 +1,156 @@
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use flate2::read::GzDecoder;
+use parking_lot::Mutex;
+use time::{Duration, OffsetDateTime};
+use tracing_rolling::{Checker, Compression, Period};
+
+mod common;
+use common::make_temp_dir;
+
+/// a [`Period`] whose `new_path` hands out a fresh, distinctly-dated-looking
+/// path on every call, so rotations can be driven as fast as the test wants
+/// instead of waiting on a real day/hour/minute boundary. this is the same
+/// trick used to reproduce timing-sensitive `MaxFiles`/`Compress` races
+/// without depending on wall-clock rotation.
+struct FakePeriod {
+    dir: PathBuf,
+    prefix: String,
+    counter: AtomicU64,
+    active: Mutex<String>,
+}
+
+impl FakePeriod {
+    fn new(dir: &Path, prefix: &str) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            prefix: prefix.to_string(),
+            counter: AtomicU64::new(0),
+            active: Mutex::new(String::new()),
+        }
+    }
+}
+
+impl Period for FakePeriod {
+    fn previous_dt(&self) -> Result<OffsetDateTime, String> {
+        self.parse_dt(&self.active.lock())
+    }
+
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::UNIX_EPOCH
+    }
+
+    fn new_path(&self) -> String {
+        let n = self.counter.fetch_add(1, Ordering::Relaxed) + 1;
+        let file = self
+            .dir
+            .join(format!("{}-{n}.log", self.prefix))
+            .display()
+            .to_string();
+        *self.active.lock() = file.clone();
+        file
+    }
+
+    fn duration(&self) -> &Duration {
+        &Duration::DAY
+    }
+
+    fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn parse_dt(&self, path: &str) -> Result<OffsetDateTime, String> {
+        let stem = Path::new(path)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| format!("no file stem in {path}"))?;
+        let n: i64 = stem
+            .rsplit('-')
+            .next()
+            .ok_or_else(|| format!("no index in {path}"))?
+            .parse()
+            .map_err(|e| format!("bad index in {path}: {e}"))?;
+        Ok(OffsetDateTime::UNIX_EPOCH + Duration::seconds(n))
+    }
+
+    fn current_file(&self) -> Option<PathBuf> {
+        let active = self.active.lock();
+        (!active.is_empty()).then(|| PathBuf::from(active.clone()))
+    }
+}
+
+/// mirrors `Rolling::update_writer`'s call order, without needing a real
+/// `Rolling<_, _>` (which would require waiting on the period's own
+/// schedule to actually rotate)
+fn simulate_rotation<C: Checker>(checker: &C) -> std::io::Result<C::W> {
+    let retiring = checker.current_path();
+    let writer = checker.new_writer()?;
+    if let Some(path) = retiring {
+        checker.on_retire(path);
+    }
+    Ok(writer)
+}
+
+#[test]
+fn compress_produces_gz_sibling_and_removes_original() {
+    let dir = make_temp_dir("compress_basic");
+    let checker = FakePeriod::new(&dir, "app").compress(Compression::Gzip);
+
+    let mut f1 = simulate_rotation(&checker).unwrap();
+    let path1 = checker.current_path().unwrap();
+    f1.write_all(b"segment-one").unwrap();
+    f1.flush().unwrap();
+    drop(f1);
+
+    let _f2 = simulate_rotation(&checker).unwrap();
+    drop(checker); // blocks until the compress worker has drained its queue
+
+    assert!(!path1.exists(), "original should be removed after compression");
+    let gz_path = PathBuf::from(format!("{}.gz", path1.display()));
+    assert!(gz_path.exists(), "expected compressed sibling at {gz_path:?}");
+
+    let mut content = String::new();
+    GzDecoder::new(fs::File::open(&gz_path).unwrap())
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(content, "segment-one");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn max_files_does_not_lose_a_file_handed_off_to_compress() {
+    let dir = make_temp_dir("compress_max_files");
+    let checker = FakePeriod::new(&dir, "app")
+        .max_files(1)
+        .compress(Compression::Gzip);
+
+    let mut f1 = simulate_rotation(&checker).unwrap();
+    let path1 = checker.current_path().unwrap();
+    f1.write_all(b"segment-one").unwrap();
+    f1.flush().unwrap();
+    drop(f1);
+
+    // this rotation retires path1 while max_files(1) is in effect; before
+    // the fix, MaxFiles's cleanup (running inside new_writer, before
+    // on_retire is even called) would delete path1 out from under the
+    // compressor, losing the segment entirely.
+    let _f2 = simulate_rotation(&checker).unwrap();
+    drop(checker);
+
+    let gz_path = PathBuf::from(format!("{}.gz", path1.display()));
+    assert!(
+        gz_path.exists(),
+        "retiring file's data was lost instead of being compressed: {gz_path:?}"
+    );
+    let mut content = String::new();
+    GzDecoder::new(fs::File::open(&gz_path).unwrap())
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(content, "segment-one");
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tracing::{debug, error, info};
+use tracing_rolling::{RollingBuilder, RollingMakeWriterExt, Rotation};
+use tracing_subscriber::fmt;
+
+mod common;
+use common::make_temp_dir;
+
+fn only_file_in(dir: &std::path::Path) -> PathBuf {
+    let mut entries: Vec<_> = fs::read_dir(dir).unwrap().map(|e| e.unwrap().path()).collect();
+    assert_eq!(entries.len(), 1, "expected exactly one rolled file, found {entries:?}");
+    entries.pop().unwrap()
+}
+
+#[test]
+fn with_max_level_keeps_only_events_as_severe_or_more_than_threshold() {
+    let dir = make_temp_dir("level_filter_max");
+    let (rolling, token) = RollingBuilder::new()
+        .dir(&dir)
+        .prefix("svc")
+        .rotation(Rotation::Daily)
+        .build()
+        .unwrap();
+    let writer = rolling.with_max_level(tracing::Level::WARN);
+    let subscriber = fmt().with_writer(writer).with_ansi(false).finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        error!("should be kept");
+        info!("should be dropped");
+    });
+    drop(token);
+
+    let contents = fs::read_to_string(only_file_in(&dir)).unwrap();
+    assert!(contents.contains("should be kept"));
+    assert!(!contents.contains("should be dropped"));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn with_min_level_keeps_only_events_as_verbose_or_more_than_threshold() {
+    let dir = make_temp_dir("level_filter_min");
+    let (rolling, token) = RollingBuilder::new()
+        .dir(&dir)
+        .prefix("svc")
+        .rotation(Rotation::Daily)
+        .build()
+        .unwrap();
+    let writer = rolling.with_min_level(tracing::Level::DEBUG);
+    let subscriber = fmt()
+        .with_writer(writer)
+        .with_max_level(tracing::Level::TRACE)
+        .with_ansi(false)
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        debug!("should be kept");
+        error!("should be dropped");
+    });
+    drop(token);
+
+    let contents = fs::read_to_string(only_file_in(&dir)).unwrap();
+    assert!(contents.contains("should be kept"));
+    assert!(!contents.contains("should be dropped"));
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::io::Write;
+
+use time::UtcOffset;
+use tracing_rolling::{Checker, Daily, NonBlockingBuilder, OverflowStrategy};
+use tracing_subscriber::fmt::MakeWriter;
+
+mod common;
+use common::make_temp_dir;
+
+fn only_file_in(dir: &std::path::Path) -> std::path::PathBuf {
+    let mut entries: Vec<_> = fs::read_dir(dir).unwrap().map(|e| e.unwrap().path()).collect();
+    assert_eq!(entries.len(), 1, "expected exactly one rolled file, found {entries:?}");
+    entries.pop().unwrap()
+}
+
+#[test]
+fn guard_drop_flushes_buffered_writes_to_disk() {
+    let dir = make_temp_dir("non_blocking_basic");
+    let path = dir.join("app.log");
+    let checker = Daily::new::<String>(&path, None, UtcOffset::UTC);
+    let (rolling, token) = checker.build().unwrap();
+    let (writer, guard) = rolling.non_blocking();
+
+    writer.make_writer().write_all(b"hello non-blocking\n").unwrap();
+    drop(guard);
+    drop(token);
+
+    let contents = fs::read_to_string(only_file_in(&dir)).unwrap();
+    assert_eq!(contents, "hello non-blocking\n");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn block_on_full_never_silently_drops_writes() {
+    let dir = make_temp_dir("non_blocking_block");
+    let path = dir.join("app.log");
+    let checker = Daily::new::<String>(&path, None, UtcOffset::UTC);
+    let (rolling, token) = checker.build().unwrap();
+    let (writer, guard) = NonBlockingBuilder::default()
+        .capacity(1)
+        .overflow(OverflowStrategy::BlockOnFull)
+        .finish(rolling);
+
+    let line = b"x".repeat(64);
+    let mut w = writer.make_writer();
+    for _ in 0..200 {
+        w.write_all(&line).unwrap();
+    }
+    drop(guard);
+    drop(token);
+
+    let contents = fs::read(only_file_in(&dir)).unwrap();
+    assert_eq!(
+        contents.len(),
+        200 * line.len(),
+        "BlockOnFull must never drop a write even with a tiny channel"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
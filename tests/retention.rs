@@ -0,0 +1,40 @@
+use std::fs;
+use std::path::Path;
+
+use time::UtcOffset;
+use tracing_rolling::{Checker, Daily, Hourly, Minute, Period};
+
+mod common;
+use common::make_temp_dir;
+
+#[test]
+fn bare_filename_dir_defaults_to_cwd() {
+    assert_eq!(
+        Daily::new::<String>("bare.log", None, UtcOffset::UTC).dir(),
+        Path::new(".")
+    );
+    assert_eq!(Minute::new("bare.log", UtcOffset::UTC).dir(), Path::new("."));
+    assert_eq!(Hourly::new("bare.log", UtcOffset::UTC).dir(), Path::new("."));
+}
+
+#[test]
+fn max_files_keeps_only_the_newest_n_rolled_files() {
+    let dir = make_temp_dir("max_files");
+    let path = dir.join("app.log");
+    // pre-seed two older rotated files matching the period's own format
+    fs::write(dir.join("app-2020-01-01.log"), b"old").unwrap();
+    fs::write(dir.join("app-2020-01-02.log"), b"old").unwrap();
+
+    let checker = Daily::new::<String>(&path, None, UtcOffset::UTC).max_files(2);
+    // opening today's file should trim the directory down to 2 files total
+    let _writer = checker.new_writer().unwrap();
+
+    let names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(names.len(), 2, "expected exactly 2 files, found {names:?}");
+    assert!(!names.contains(&"app-2020-01-01.log".to_string()));
+
+    fs::remove_dir_all(&dir).ok();
+}
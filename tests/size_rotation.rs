@@ -0,0 +1,30 @@
+use std::fs;
+
+use time::UtcOffset;
+use tracing_rolling::{Checker, Daily, Period};
+
+mod common;
+use common::make_temp_dir;
+
+#[test]
+fn by_size_appends_an_incrementing_index_within_the_same_period() {
+    let dir = make_temp_dir("by_size");
+    let path = dir.join("app.log");
+    let checker = Daily::new::<String>(&path, None, UtcOffset::UTC).by_size(10);
+
+    for _ in 0..3 {
+        let _ = checker.new_writer().unwrap();
+    }
+
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+    assert_eq!(names.len(), 3, "expected 3 distinct rotated files, found {names:?}");
+    assert!(names[0].ends_with(".1.log"));
+    assert!(names[1].ends_with(".2.log"));
+    assert!(names[2].ends_with(".3.log"));
+
+    fs::remove_dir_all(&dir).ok();
+}